@@ -22,31 +22,152 @@
 //! use std::cmp::max;
 //! println!("The newest version is {}", max(v1, v2));
 //! ```
+//!
+//! Use [`VersionReq`] to check a [`SemVer`] against a requirement string, including caret,
+//! tilde, x-range, hyphen, and `||` sugar:
+//!
+//! ```rust
+//! use yad_semver::{SemVer, VersionReq};
+//!
+//! let req: VersionReq = "^1.2.3".parse().unwrap();
+//! assert!(req.matches(&"1.9.0".parse::<SemVer>().unwrap()));
+//! ```
+//!
+//! Enable the `serde` feature to (de)serialize a `SemVer` as its canonical string.
 
 use std::str::FromStr;
 use lazy_static::lazy_static;
 use regex::Regex;
-use std::cmp::min;
 use std::fmt::Display;
 
+mod version_req;
+pub use version_req::{VersionReq, VersionReqParseError, Comparator, ComparatorParseError, Operator};
+
+#[cfg(feature = "serde")]
+mod serde;
+
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone)]
+pub enum Identifier
+{
+    Numeric(u128),
+    AlphaNumeric(String),
+}
+
+
+impl Identifier
+{
+    fn parse(s: &str) -> Self
+    {
+        match s.parse::<u128>()
+        {
+            Ok(n) => Self::Numeric(n),
+            Err(_) => Self::AlphaNumeric(s.to_owned()),
+        }
+    }
+}
+
+
+impl Display for Identifier
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        match self
+        {
+            Self::Numeric(n) => write!(f, "{}", n),
+            Self::AlphaNumeric(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+
+pub(crate) fn parse_pre_release(s: &str) -> Vec<Identifier>
+{
+    s.split(".").map(Identifier::parse).collect()
+}
+
+
+fn display_pre_release(pre_release: &[Identifier]) -> String
+{
+    pre_release.iter().map(|i| i.to_string()).collect::<Vec<String>>().join(".")
+}
+
 
-#[derive(PartialEq, Eq, Ord)]
+#[derive(PartialEq, Eq)]
 pub struct SemVer
 {
     pub major: u128,
     pub minor: u128,
     pub patch: u128,
-    pub pre_release: Option<String>,
+    pub pre_release: Option<Vec<Identifier>>,
     pub build_meta: Option<String>,
 }
 
 
+/// Which component of a [`SemVer`] to [`SemVer::bump`].
+pub enum Part
+{
+    Major,
+    Minor,
+    Patch,
+}
+
+
 impl SemVer
 {
-    pub fn new(major: u128, minor: u128, patch: u128, pre_release: Option<String>, build_meta: Option<String>) -> Self
+    pub fn new(major: u128, minor: u128, patch: u128, pre_release: Option<Vec<Identifier>>, build_meta: Option<String>) -> Self
     {
         Self { major, minor, patch, pre_release, build_meta }
     }
+
+
+    pub fn is_prerelease(&self) -> bool
+    {
+        self.pre_release.is_some()
+    }
+
+
+    /// Drops any pre-release and build metadata, leaving major.minor.patch untouched.
+    pub fn clear_metadata(&mut self)
+    {
+        self.pre_release = None;
+        self.build_meta = None;
+    }
+
+
+    pub fn increment_major(&mut self)
+    {
+        self.major += 1;
+        self.minor = 0;
+        self.patch = 0;
+        self.clear_metadata();
+    }
+
+
+    pub fn increment_minor(&mut self)
+    {
+        self.minor += 1;
+        self.patch = 0;
+        self.clear_metadata();
+    }
+
+
+    pub fn increment_patch(&mut self)
+    {
+        self.patch += 1;
+        self.clear_metadata();
+    }
+
+
+    pub fn bump(&mut self, part: Part)
+    {
+        match part
+        {
+            Part::Major => self.increment_major(),
+            Part::Minor => self.increment_minor(),
+            Part::Patch => self.increment_patch(),
+        }
+    }
 }
 
 
@@ -81,11 +202,11 @@ impl Display for SemVer
         {
             (Some(pre_release), Some(build_meta)) =>
             {
-                f.write_fmt(format_args!("{}.{}.{}-{}+{}", self.major, self.minor, self.patch, pre_release, build_meta))
+                f.write_fmt(format_args!("{}.{}.{}-{}+{}", self.major, self.minor, self.patch, display_pre_release(pre_release), build_meta))
             }
             (Some(pre_release), None) =>
             {
-                f.write_fmt(format_args!("{}.{}.{}-{}", self.major, self.minor, self.patch, pre_release))
+                f.write_fmt(format_args!("{}.{}.{}-{}", self.major, self.minor, self.patch, display_pre_release(pre_release)))
             }
             (None, Some(build_meta)) =>
             {
@@ -125,106 +246,40 @@ impl FromStr for SemVer
         let Ok(minor) = minor.as_str().parse() else { return Err(error); };
         let Ok(patch) = patch.as_str().parse() else { return Err(error); };
 
-        let pre_release = captures.name("prerelease").and_then(|m| Some(m.as_str().to_owned()));
-        let build_meta = captures.name("buildmetadata").and_then(|m| Some(m.as_str().to_owned()));
+        let pre_release = captures.name("prerelease").map(|m| parse_pre_release(m.as_str()));
+        let build_meta = captures.name("buildmetadata").map(|m| m.as_str().to_owned());
 
         Ok(Self { major, minor, patch, pre_release, build_meta })
     }
 }
 
 
-impl PartialOrd for SemVer
+impl Ord for SemVer
 {
-    fn ge(&self, other: &Self) -> bool
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering
     {
-        // Simple check
-        if
-            (self.major > other.major) ||
-            (self.minor > other.minor) ||
-            (self.patch > other.patch) ||
-            (self.pre_release.is_none() && other.pre_release.is_some())
-        {
-            return true;
-        }
+        use std::cmp::Ordering;
 
-        // More complicated checks
-        let ours = &self.pre_release;
-        let theirs = &other.pre_release;
-
-        if ours.is_some() && theirs.is_some()
-        {
-            let ours = ours.as_ref().unwrap().split(".").collect::<Vec<&str>>();
-            let theirs = theirs.as_ref().unwrap().split(".").collect::<Vec<&str>>();
-
-            for i in 0..min(ours.len(), theirs.len())
+        // Build metadata MUST be ignored when determining precedence.
+        self.major.cmp(&other.major)
+            .then(self.minor.cmp(&other.minor))
+            .then(self.patch.cmp(&other.patch))
+            .then_with(|| match (&self.pre_release, &other.pre_release)
             {
-                let ours = ours[i];
-                let theirs = theirs[i];
-
-                if ours == theirs { continue; }
-
-                let ours_num = ours.parse::<u128>();
-                let theirs_num = theirs.parse::<u128>();
-
-                return match (ours_num.is_ok(), theirs_num.is_ok())
-                {
-                    (true, true) =>
-                    {
-                        let ours_num = ours_num.unwrap();
-                        let theirs_num = theirs_num.unwrap();
-
-                        ours_num > theirs_num
-                    }
-                    (true, false) => false,
-                    (false, true) => true,
-                    (false, false) => ours > theirs,
-                };
-            }
-
-            // If we are at this point, then all the prerelease fields are equal
-            return ours.len() > theirs.len();
-        }
-        else if ours.is_none() && theirs.is_some()
-        {
-            return true;
-        }
-
-        return false;
-    }
-
-
-    fn gt(&self, other: &Self) -> bool
-    {
-        self == other && self.ge(other)
-    }
-
-
-    fn le(&self, other: &Self) -> bool
-    {
-        other.gt(self)
-    }
-
-
-    fn lt(&self, other: &Self) -> bool
-    {
-        other.ge(self)
+                (None, None) => Ordering::Equal,
+                (None, Some(_)) => Ordering::Greater,
+                (Some(_), None) => Ordering::Less,
+                (Some(ours), Some(theirs)) => ours.cmp(theirs),
+            })
     }
+}
 
 
+impl PartialOrd for SemVer
+{
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering>
     {
-        if self == other
-        {
-            Some(std::cmp::Ordering::Equal)
-        }
-        else if self < other
-        {
-            Some(std::cmp::Ordering::Less)
-        }
-        else
-        {
-            Some(std::cmp::Ordering::Greater)
-        }
+        Some(self.cmp(other))
     }
 }
 
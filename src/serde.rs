@@ -0,0 +1,50 @@
+use std::fmt;
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::SemVer;
+
+
+impl Serialize for SemVer
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+
+struct SemVerVisitor;
+
+
+impl<'de> de::Visitor<'de> for SemVerVisitor
+{
+    type Value = SemVer;
+
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result
+    {
+        f.write_str("a semver string")
+    }
+
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        v.parse().map_err(|e: crate::SemVerParseError| E::custom(format!("invalid semver: {}", e.version)))
+    }
+}
+
+
+impl<'de> Deserialize<'de> for SemVer
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(SemVerVisitor)
+    }
+}
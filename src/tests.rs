@@ -1,4 +1,6 @@
 use crate::SemVer;
+use crate::VersionReq;
+use crate::Part;
 
 const VALID_STRINGS: [&'static str; 31] =
 [
@@ -136,4 +138,271 @@ fn precedence()
             assert!(semvers[i].partial_cmp(&semvers[j]) == i.partial_cmp(&j))
         }
     }
-}
\ No newline at end of file
+}
+
+
+#[test]
+fn higher_major_outranks_higher_minor_or_patch()
+{
+    let v1 = "1.0.5".parse::<SemVer>().unwrap();
+    let v2 = "2.0.0".parse::<SemVer>().unwrap();
+
+    assert!(v1 < v2);
+    assert!(v1 <= v2);
+}
+
+
+#[test]
+fn build_metadata_does_not_affect_precedence()
+{
+    let v1 = "1.0.0+build.1".parse::<SemVer>().unwrap();
+    let v2 = "1.0.0+build.2".parse::<SemVer>().unwrap();
+
+    assert_eq!(v1.partial_cmp(&v2), Some(std::cmp::Ordering::Equal));
+    assert!(v1 != v2);
+}
+
+
+#[test]
+fn numeric_pre_release_identifiers_sort_below_alphanumeric()
+{
+    let numeric = "1.0.0-1".parse::<SemVer>().unwrap();
+    let alpha = "1.0.0-alpha".parse::<SemVer>().unwrap();
+
+    assert!(numeric < alpha);
+}
+
+
+#[test]
+fn increment_major_resets_minor_and_patch_and_clears_metadata()
+{
+    let mut v = "1.2.3-alpha+build".parse::<SemVer>().unwrap();
+    v.increment_major();
+
+    assert_eq!(v.to_string(), "2.0.0");
+}
+
+
+#[test]
+fn increment_minor_resets_patch_and_clears_metadata()
+{
+    let mut v = "1.2.3-alpha+build".parse::<SemVer>().unwrap();
+    v.increment_minor();
+
+    assert_eq!(v.to_string(), "1.3.0");
+}
+
+
+#[test]
+fn increment_patch_clears_metadata()
+{
+    let mut v = "1.2.3-alpha+build".parse::<SemVer>().unwrap();
+    v.increment_patch();
+
+    assert_eq!(v.to_string(), "1.2.4");
+}
+
+
+#[test]
+fn bump_dispatches_to_the_matching_increment()
+{
+    let mut v = "1.2.3".parse::<SemVer>().unwrap();
+    v.bump(Part::Minor);
+
+    assert_eq!(v.to_string(), "1.3.0");
+}
+
+
+#[test]
+fn is_prerelease_reflects_pre_release_presence()
+{
+    assert!("1.0.0-alpha".parse::<SemVer>().unwrap().is_prerelease());
+    assert!(!"1.0.0".parse::<SemVer>().unwrap().is_prerelease());
+}
+
+
+#[test]
+fn version_req_matches_single_comparators()
+{
+    let req = ">=1.2".parse::<VersionReq>().unwrap();
+
+    assert!(req.matches(&"1.2.0".parse::<SemVer>().unwrap()));
+    assert!(req.matches(&"1.3.0".parse::<SemVer>().unwrap()));
+    assert!(!req.matches(&"1.1.9".parse::<SemVer>().unwrap()));
+}
+
+
+#[test]
+fn version_req_matches_all_comparators()
+{
+    let req = ">=1.2.0,<2.0.0".parse::<VersionReq>().unwrap();
+
+    assert!(req.matches(&"1.2.3".parse::<SemVer>().unwrap()));
+    assert!(!req.matches(&"1.1.9".parse::<SemVer>().unwrap()));
+    assert!(!req.matches(&"2.0.0".parse::<SemVer>().unwrap()));
+}
+
+
+#[test]
+fn version_req_excludes_unrelated_prereleases()
+{
+    let req = ">=1.0.0".parse::<VersionReq>().unwrap();
+
+    assert!(!req.matches(&"2.0.0-beta".parse::<SemVer>().unwrap()));
+    assert!(!req.matches(&"1.2.3-alpha".parse::<SemVer>().unwrap()));
+}
+
+
+#[test]
+fn version_req_prerelease_allowed_by_any_comparator_in_its_set()
+{
+    let req = ">=1.2.3-alpha,<2.0.0".parse::<VersionReq>().unwrap();
+
+    assert!(req.matches(&"1.2.3-alpha".parse::<SemVer>().unwrap()));
+    assert!(!req.matches(&"2.0.0-beta".parse::<SemVer>().unwrap()));
+}
+
+
+#[test]
+fn version_req_exact_ignores_build_metadata()
+{
+    let req = "=1.2.3".parse::<VersionReq>().unwrap();
+
+    assert!(req.matches(&"1.2.3+build.5".parse::<SemVer>().unwrap()));
+    assert!(!req.matches(&"1.2.4".parse::<SemVer>().unwrap()));
+}
+
+
+#[test]
+fn version_req_matches_comparator_with_own_prerelease()
+{
+    let req = ">=1.2.3-alpha".parse::<VersionReq>().unwrap();
+
+    assert!(req.matches(&"1.2.3-alpha".parse::<SemVer>().unwrap()));
+    assert!(req.matches(&"1.2.3-beta".parse::<SemVer>().unwrap()));
+    assert!(!req.matches(&"1.2.4-alpha".parse::<SemVer>().unwrap()));
+}
+
+
+#[test]
+fn version_req_caret_ranges()
+{
+    let cases =
+    [
+        ("^1.2.3", "1.2.3", true),
+        ("^1.2.3", "1.9.9", true),
+        ("^1.2.3", "2.0.0", false),
+        ("^0.2.3", "0.2.9", true),
+        ("^0.2.3", "0.3.0", false),
+        ("^0.0.3", "0.0.3", true),
+        ("^0.0.3", "0.0.4", false),
+        ("^0.x", "0.9.9", true),
+        ("^0.x", "1.0.0", false),
+        ("^0.0", "0.0.9", true),
+        ("^0.0", "0.1.0", false),
+        ("^0.0.x", "0.0.9", true),
+        ("^0.0.x", "0.1.0", false),
+        ("^1.2.3-alpha", "1.2.3-alpha", true),
+        ("^1.2.3-alpha", "1.2.3-beta", true),
+        ("^1.2.3-alpha", "1.2.4-alpha", false),
+    ];
+
+    for (req, version, expected) in cases
+    {
+        let parsed = req.parse::<VersionReq>().unwrap();
+        assert_eq!(parsed.matches(&version.parse::<SemVer>().unwrap()), expected, "{req} vs {version}");
+    }
+}
+
+
+#[test]
+fn version_req_tilde_ranges()
+{
+    let cases =
+    [
+        ("~1.2.3", "1.2.9", true),
+        ("~1.2.3", "1.3.0", false),
+        ("~1.2", "1.2.0", true),
+        ("~1.2", "1.3.0", false),
+        ("~1.2.3-alpha", "1.2.3-alpha", true),
+        ("~1.2.3-alpha", "1.2.3-beta", true),
+        ("~1.2.3-alpha", "1.3.0-alpha", false),
+    ];
+
+    for (req, version, expected) in cases
+    {
+        let parsed = req.parse::<VersionReq>().unwrap();
+        assert_eq!(parsed.matches(&version.parse::<SemVer>().unwrap()), expected, "{req} vs {version}");
+    }
+}
+
+
+#[test]
+fn version_req_x_ranges()
+{
+    let cases =
+    [
+        ("1.2.*", "1.2.9", true),
+        ("1.2.*", "1.3.0", false),
+        ("1.*", "1.9.9", true),
+        ("1.*", "2.0.0", false),
+        ("*", "5.6.7", true),
+    ];
+
+    for (req, version, expected) in cases
+    {
+        let parsed = req.parse::<VersionReq>().unwrap();
+        assert_eq!(parsed.matches(&version.parse::<SemVer>().unwrap()), expected, "{req} vs {version}");
+    }
+}
+
+
+#[test]
+fn version_req_hyphen_ranges()
+{
+    let full = "1.2.3 - 2.3.4".parse::<VersionReq>().unwrap();
+    assert!(full.matches(&"2.3.4".parse::<SemVer>().unwrap()));
+    assert!(!full.matches(&"2.3.5".parse::<SemVer>().unwrap()));
+
+    let partial = "1.2 - 2.3".parse::<VersionReq>().unwrap();
+    assert!(partial.matches(&"2.3.9".parse::<SemVer>().unwrap()));
+    assert!(!partial.matches(&"2.4.0".parse::<SemVer>().unwrap()));
+
+    let with_prerelease = "1.2.3-alpha - 2.0.0".parse::<VersionReq>().unwrap();
+    assert!(with_prerelease.matches(&"1.2.3-alpha".parse::<SemVer>().unwrap()));
+    assert!(!with_prerelease.matches(&"1.2.2-alpha".parse::<SemVer>().unwrap()));
+}
+
+
+#[test]
+fn version_req_alternatives()
+{
+    let req = "1.2.3 || ^2.0.0".parse::<VersionReq>().unwrap();
+
+    assert!(req.matches(&"1.2.3".parse::<SemVer>().unwrap()));
+    assert!(req.matches(&"2.5.0".parse::<SemVer>().unwrap()));
+    assert!(!req.matches(&"1.2.4".parse::<SemVer>().unwrap()));
+    assert!(!req.matches(&"3.0.0".parse::<SemVer>().unwrap()));
+}
+
+
+#[test]
+#[cfg(feature = "serde")]
+fn serde_round_trips_through_the_canonical_string()
+{
+    let v = "1.2.3-alpha.1+build.1".parse::<SemVer>().unwrap();
+
+    let json = serde_json::to_string(&v).unwrap();
+    assert_eq!(json, "\"1.2.3-alpha.1+build.1\"");
+
+    let round_tripped: SemVer = serde_json::from_str(&json).unwrap();
+    assert!(round_tripped == v);
+}
+
+
+#[test]
+#[cfg(feature = "serde")]
+fn serde_rejects_an_invalid_string()
+{
+    assert!(serde_json::from_str::<SemVer>("\"not a semver\"").is_err());
+}
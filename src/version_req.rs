@@ -0,0 +1,347 @@
+use std::str::FromStr;
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use crate::{SemVer, parse_pre_release};
+
+
+/// The relation a [`Comparator`] checks its operand against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operator
+{
+    Exact,
+    Greater,
+    GreaterEq,
+    Less,
+    LessEq,
+}
+
+
+/// A single `<op><version>` check, e.g. `>=1.2.3`.
+#[derive(Clone)]
+pub struct Comparator
+{
+    pub op: Operator,
+    pub version: SemVer,
+}
+
+
+impl Comparator
+{
+    fn matches(&self, v: &SemVer) -> bool
+    {
+        match self.op
+        {
+            Operator::Exact => v.cmp(&self.version) == std::cmp::Ordering::Equal,
+            Operator::Greater => v > &self.version,
+            Operator::GreaterEq => v >= &self.version,
+            Operator::Less => v < &self.version,
+            Operator::LessEq => v <= &self.version,
+        }
+    }
+}
+
+
+// A pre-release version is only allowed to satisfy a comparator set if some comparator in
+// that set shares its major.minor.patch and is itself a pre-release.
+fn set_allows_prerelease(set: &[Comparator], v: &SemVer) -> bool
+{
+    set.iter().any(|c|
+    {
+        v.major == c.version.major &&
+        v.minor == c.version.minor &&
+        v.patch == c.version.patch &&
+        c.version.pre_release.is_some()
+    })
+}
+
+
+/// Returned when a string doesn't parse as a [`Comparator`].
+#[derive(Debug)]
+pub struct ComparatorParseError
+{
+    pub comparator: String,
+}
+
+
+impl FromStr for Comparator
+{
+    type Err = ComparatorParseError;
+
+
+    fn from_str(s: &str) -> Result<Self, Self::Err>
+    {
+        let error = Self::Err { comparator: s.to_owned() };
+
+        lazy_static!
+        {
+            static ref RE: Regex = Regex::new(r"^(?P<op>=|>=|<=|>|<)\s*(?P<major>0|[1-9]\d*)(?:\.(?P<minor>0|[1-9]\d*))?(?:\.(?P<patch>0|[1-9]\d*))?(?:-(?P<prerelease>(?:0|[1-9]\d*|\d*[a-zA-Z-][0-9a-zA-Z-]*)(?:\.(?:0|[1-9]\d*|\d*[a-zA-Z-][0-9a-zA-Z-]*))*))?$").unwrap();
+        }
+
+        let Some(captures) = RE.captures(s.trim()) else { return Err(error); };
+
+        let op = match captures.name("op").unwrap().as_str()
+        {
+            "=" => Operator::Exact,
+            ">" => Operator::Greater,
+            ">=" => Operator::GreaterEq,
+            "<" => Operator::Less,
+            "<=" => Operator::LessEq,
+            _ => unreachable!(),
+        };
+
+        let Some(major) = captures.name("major") else { return Err(error); };
+        let Ok(major) = major.as_str().parse() else { return Err(error); };
+
+        let minor = captures.name("minor").and_then(|m| m.as_str().parse().ok()).unwrap_or(0);
+        let patch = captures.name("patch").and_then(|m| m.as_str().parse().ok()).unwrap_or(0);
+
+        let pre_release = captures.name("prerelease").map(|m| parse_pre_release(m.as_str()));
+
+        Ok(Self { op, version: SemVer::new(major, minor, patch, pre_release, None) })
+    }
+}
+
+
+/// A version requirement: a `||`-separated list of comparator sets, all of whose
+/// comparators must match for that set to match (as in `>=1.2.0,<2.0.0`).
+#[derive(Clone)]
+pub struct VersionReq
+{
+    pub comparator_sets: Vec<Vec<Comparator>>,
+}
+
+
+impl VersionReq
+{
+    /// Returns `true` if `v` satisfies at least one of the comparator sets. A pre-release `v`
+    /// only counts for a set that has a comparator with the same major.minor.patch which itself
+    /// carries a pre-release, so `>=1.0.0` does not match `2.0.0-beta`.
+    pub fn matches(&self, v: &SemVer) -> bool
+    {
+        self.comparator_sets.iter().any(|set|
+        {
+            if v.pre_release.is_some() && !set_allows_prerelease(set, v)
+            {
+                return false;
+            }
+
+            set.iter().all(|c| c.matches(v))
+        })
+    }
+}
+
+
+/// Returned when a string doesn't parse as a [`VersionReq`].
+#[derive(Debug)]
+pub struct VersionReqParseError
+{
+    pub req: String,
+}
+
+
+/// Parses a requirement string. Supports plain comparators (`>=1.2.3`), comma-separated
+/// comparator sets (`>=1.2.0,<2.0.0`), `||`-separated alternatives (`1.2.3 || ^2.0.0`), caret
+/// ranges (`^1.2.3`), tilde ranges (`~1.2.3`), x-ranges (`1.2.*`, `1.*`, `*`), and hyphen
+/// ranges (`1.2.3 - 2.3.4`).
+impl FromStr for VersionReq
+{
+    type Err = VersionReqParseError;
+
+
+    fn from_str(s: &str) -> Result<Self, Self::Err>
+    {
+        let error = Self::Err { req: s.to_owned() };
+
+        let mut comparator_sets = Vec::new();
+
+        for set in s.split("||")
+        {
+            let Some(comparators) = parse_comparator_set(set.trim()) else { return Err(error); };
+            comparator_sets.push(comparators);
+        }
+
+        if comparator_sets.is_empty() { return Err(error); }
+
+        Ok(Self { comparator_sets })
+    }
+}
+
+
+// A partial version, as found in range sugar: missing or `x`/`X`/`*` components
+// stand for "unconstrained" rather than zero.
+struct PartialVersion
+{
+    major: Option<u128>,
+    minor: Option<u128>,
+    patch: Option<u128>,
+    pre_release: Option<String>,
+}
+
+
+fn parse_partial(s: &str) -> Option<PartialVersion>
+{
+    lazy_static!
+    {
+        static ref RE: Regex = Regex::new(r"^(?P<major>\d+|[xX*])(?:\.(?P<minor>\d+|[xX*]))?(?:\.(?P<patch>\d+|[xX*]))?(?:-(?P<prerelease>(?:0|[1-9]\d*|\d*[a-zA-Z-][0-9a-zA-Z-]*)(?:\.(?:0|[1-9]\d*|\d*[a-zA-Z-][0-9a-zA-Z-]*))*))?$").unwrap();
+    }
+
+    let captures = RE.captures(s.trim())?;
+
+    let component = |name: &str| -> Option<u128>
+    {
+        captures.name(name).and_then(|m| m.as_str().parse().ok())
+    };
+
+    let pre_release = captures.name("prerelease").map(|m| m.as_str().to_owned());
+
+    Some(PartialVersion { major: component("major"), minor: component("minor"), patch: component("patch"), pre_release })
+}
+
+
+fn caret_comparators(p: PartialVersion) -> Vec<Comparator>
+{
+    let major = p.major.unwrap_or(0);
+    let minor = p.minor.unwrap_or(0);
+    let patch = p.patch.unwrap_or(0);
+
+    let upper = if major != 0
+    {
+        SemVer::new(major + 1, 0, 0, None, None)
+    }
+    else if p.minor.is_none()
+    {
+        SemVer::new(1, 0, 0, None, None)
+    }
+    else if minor != 0
+    {
+        SemVer::new(0, minor + 1, 0, None, None)
+    }
+    else if p.patch.is_none()
+    {
+        SemVer::new(0, 1, 0, None, None)
+    }
+    else
+    {
+        SemVer::new(0, 0, patch + 1, None, None)
+    };
+
+    vec!
+    [
+        Comparator { op: Operator::GreaterEq, version: SemVer::new(major, minor, patch, p.pre_release.as_deref().map(parse_pre_release), None) },
+        Comparator { op: Operator::Less, version: upper },
+    ]
+}
+
+
+fn tilde_comparators(p: PartialVersion) -> Vec<Comparator>
+{
+    let major = p.major.unwrap_or(0);
+    let minor = p.minor.unwrap_or(0);
+    let patch = p.patch.unwrap_or(0);
+
+    let upper = if p.minor.is_some()
+    {
+        SemVer::new(major, minor + 1, 0, None, None)
+    }
+    else
+    {
+        SemVer::new(major + 1, 0, 0, None, None)
+    };
+
+    vec!
+    [
+        Comparator { op: Operator::GreaterEq, version: SemVer::new(major, minor, patch, p.pre_release.as_deref().map(parse_pre_release), None) },
+        Comparator { op: Operator::Less, version: upper },
+    ]
+}
+
+
+fn x_range_comparators(p: PartialVersion) -> Vec<Comparator>
+{
+    let Some(major) = p.major else { return Vec::new(); };
+
+    let Some(minor) = p.minor else
+    {
+        return vec!
+        [
+            Comparator { op: Operator::GreaterEq, version: SemVer::new(major, 0, 0, None, None) },
+            Comparator { op: Operator::Less, version: SemVer::new(major + 1, 0, 0, None, None) },
+        ];
+    };
+
+    let Some(patch) = p.patch else
+    {
+        return vec!
+        [
+            Comparator { op: Operator::GreaterEq, version: SemVer::new(major, minor, 0, None, None) },
+            Comparator { op: Operator::Less, version: SemVer::new(major, minor + 1, 0, None, None) },
+        ];
+    };
+
+    vec![Comparator { op: Operator::Exact, version: SemVer::new(major, minor, patch, p.pre_release.as_deref().map(parse_pre_release), None) }]
+}
+
+
+fn hyphen_range_comparators(lower: &str, upper: &str) -> Option<Vec<Comparator>>
+{
+    let lower = parse_partial(lower)?;
+    let upper = parse_partial(upper)?;
+
+    let lower_major = lower.major?;
+    let lower_comparator = Comparator
+    {
+        op: Operator::GreaterEq,
+        version: SemVer::new(lower_major, lower.minor.unwrap_or(0), lower.patch.unwrap_or(0), lower.pre_release.as_deref().map(parse_pre_release), None),
+    };
+
+    let upper_major = upper.major?;
+
+    let upper_comparator = match (upper.minor, upper.patch)
+    {
+        (Some(minor), Some(patch)) => Comparator { op: Operator::LessEq, version: SemVer::new(upper_major, minor, patch, upper.pre_release.as_deref().map(parse_pre_release), None) },
+        (Some(minor), None) => Comparator { op: Operator::Less, version: SemVer::new(upper_major, minor + 1, 0, None, None) },
+        (None, _) => Comparator { op: Operator::Less, version: SemVer::new(upper_major + 1, 0, 0, None, None) },
+    };
+
+    Some(vec![lower_comparator, upper_comparator])
+}
+
+
+fn parse_range_token(token: &str) -> Option<Vec<Comparator>>
+{
+    if let Some(rest) = token.strip_prefix('^')
+    {
+        return Some(caret_comparators(parse_partial(rest)?));
+    }
+
+    if let Some(rest) = token.strip_prefix('~')
+    {
+        return Some(tilde_comparators(parse_partial(rest)?));
+    }
+
+    if token.starts_with('=') || token.starts_with('>') || token.starts_with('<')
+    {
+        return token.parse::<Comparator>().ok().map(|c| vec![c]);
+    }
+
+    Some(x_range_comparators(parse_partial(token)?))
+}
+
+
+fn parse_comparator_set(set: &str) -> Option<Vec<Comparator>>
+{
+    if let Some((lower, upper)) = set.split_once(" - ")
+    {
+        return hyphen_range_comparators(lower.trim(), upper.trim());
+    }
+
+    let mut comparators = Vec::new();
+
+    for token in set.split(',')
+    {
+        comparators.extend(parse_range_token(token.trim())?);
+    }
+
+    Some(comparators)
+}